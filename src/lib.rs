@@ -1,6 +1,10 @@
+mod multi;
 mod spinner;
+mod spinners;
 mod state;
 mod term;
 
+pub use multi::{MultiSpinner, MultiSpinnerHandle};
 pub use spinner::{RunningSpinner, Spinner, StoppedSpinner};
-pub use term::{show_cursor, Color};
+pub use spinners::Spinners;
+pub use term::{parse_color, show_cursor, Color, Stream};
@@ -0,0 +1,217 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::mpsc::{channel, Sender, TryRecvError};
+use std::thread::{sleep, spawn, JoinHandle};
+use std::time::Duration;
+
+use crate::state::{State, Update};
+use crate::term;
+
+/// A single line owned by a [`MultiSpinner`].
+///
+/// Mirrors the chainable update API of [`crate::RunningSpinner`], but each
+/// call targets this handle's own line instead of a dedicated thread.
+///
+/// # Examples
+///
+/// ```
+/// use spinach::MultiSpinner;
+///
+/// let multi = MultiSpinner::new();
+/// let line = multi.add("Loading...");
+/// line.text("done!").success();
+/// multi.wait();
+/// ```
+#[derive(Clone, Debug)]
+pub struct MultiSpinnerHandle {
+    index: usize,
+    sender: Sender<(usize, Update)>,
+    update: RefCell<Update>,
+}
+
+impl MultiSpinnerHandle {
+    /// Sets the color of this line.
+    pub fn color(&self, color: term::Color) -> &Self {
+        self.update.borrow_mut().color = Some(color);
+        self
+    }
+
+    /// Sets the text displayed alongside this line's spinner.
+    pub fn text(&self, text: &str) -> &Self {
+        self.update.borrow_mut().text = Some(text.to_string());
+        self
+    }
+
+    /// Sets a single symbol for this line, useful for a final symbol.
+    pub fn symbol(&self, symbol: &'static str) -> &Self {
+        self.update.borrow_mut().symbols = Some(vec![symbol]);
+        self
+    }
+
+    /// Sends the pending changes to be rendered on this line.
+    pub fn update(&self) -> &Self {
+        _ = self.sender.send((self.index, self.update.borrow().clone()));
+        self
+    }
+
+    /// Stops this line, leaving its last rendered symbol and text in place.
+    pub fn stop(&self) {
+        self.update.borrow_mut().stop = true;
+        self.update();
+    }
+
+    /// Stops this line with a pre-configured success indication.
+    pub fn success(&self) {
+        self.update.borrow_mut().color = Some(term::Color::Green);
+        self.update.borrow_mut().symbols = Some(vec!["✔"]);
+        self.stop();
+    }
+
+    /// Stops this line with a pre-configured failure indication.
+    pub fn failure(&self) {
+        self.update.borrow_mut().color = Some(term::Color::Red);
+        self.update.borrow_mut().symbols = Some(vec!["✖"]);
+        self.stop();
+    }
+
+    /// Stops this line with a pre-configured warning indication.
+    pub fn warn(&self) {
+        self.update.borrow_mut().color = Some(term::Color::Yellow);
+        self.update.borrow_mut().symbols = Some(vec!["⚠"]);
+        self.stop();
+    }
+}
+
+/// Drives several spinners concurrently, each rendered on its own
+/// terminal line.
+///
+/// All lines must be added via [`add`](Self::add) before the last one is
+/// stopped; the render thread exits once every line added so far has
+/// stopped, so a line added afterward is never drawn.
+///
+/// # Examples
+///
+/// ```
+/// use spinach::MultiSpinner;
+///
+/// let multi = MultiSpinner::new();
+/// let first = multi.add("Downloading assets...");
+/// let second = multi.add("Compiling...");
+/// // Perform tasks on separate threads, each updating its own line.
+/// first.text("Assets downloaded").success();
+/// second.text("Compiled").success();
+/// multi.wait();
+/// ```
+#[derive(Clone, Debug)]
+pub struct MultiSpinner {
+    sender: Sender<(usize, Update)>,
+    handle: Rc<RefCell<Option<JoinHandle<()>>>>,
+    next_index: Rc<RefCell<usize>>,
+}
+
+impl Default for MultiSpinner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MultiSpinner {
+    /// Creates a new `MultiSpinner` and starts its render thread.
+    #[must_use]
+    pub fn new() -> Self {
+        let (sender, receiver) = channel::<(usize, Update)>();
+        let handle = RefCell::new(Some(spawn(move || {
+            term::hide_cursor(term::Stream::Stdout);
+            let mut states: Vec<State> = Vec::new();
+            let mut done: Vec<bool> = Vec::new();
+            let mut iteration = 0;
+            let mut rendered_lines = 0;
+            loop {
+                loop {
+                    match receiver.try_recv() {
+                        Ok((index, update)) => {
+                            while states.len() <= index {
+                                states.push(State::default());
+                                done.push(false);
+                            }
+                            let stop = update.stop;
+                            states[index].update(update);
+                            if stop {
+                                done[index] = true;
+                                // Freeze the line on the frame drawn last.
+                                let symbols = &states[index].symbols;
+                                let frame = symbols[iteration.saturating_sub(1) % symbols.len()];
+                                states[index].symbols = vec![frame];
+                            }
+                        }
+                        Err(TryRecvError::Empty) => break,
+                        Err(TryRecvError::Disconnected) => {
+                            if !states.is_empty() {
+                                render(&states, iteration, rendered_lines);
+                            }
+                            term::show_cursor_on(term::Stream::Stdout);
+                            return;
+                        }
+                    }
+                }
+                if !states.is_empty() {
+                    render(&states, iteration, rendered_lines);
+                    rendered_lines = states.len();
+                }
+                iteration += 1;
+                if !states.is_empty() && done.iter().all(|line_done| *line_done) {
+                    break;
+                }
+                sleep(Duration::from_millis(65));
+            }
+            term::show_cursor_on(term::Stream::Stdout);
+        })));
+        Self {
+            sender,
+            handle: Rc::new(handle),
+            next_index: Rc::new(RefCell::new(0)),
+        }
+    }
+
+    /// Adds a new line with the given initial text, returning a handle
+    /// used to update it independently of the other lines.
+    ///
+    /// All lines must be added before the last one is stopped: the render
+    /// thread exits once every line added so far has stopped, so a line
+    /// added after that point is never drawn.
+    pub fn add(&self, text: &str) -> MultiSpinnerHandle {
+        let mut next_index = self.next_index.borrow_mut();
+        let index = *next_index;
+        *next_index += 1;
+        _ = self.sender.send((index, Update::new(text)));
+        MultiSpinnerHandle {
+            index,
+            sender: self.sender.clone(),
+            update: RefCell::new(Update::default()),
+        }
+    }
+
+    /// Blocks until every line has been stopped and the render thread
+    /// has exited.
+    pub fn wait(&self) {
+        if let Some(handle) = self.handle.borrow_mut().take() {
+            _ = handle.join();
+        }
+    }
+}
+
+fn render(states: &[State], iteration: usize, previously_rendered: usize) {
+    term::cursor_up(term::Stream::Stdout, previously_rendered);
+    for state in states {
+        term::delete_line(term::Stream::Stdout);
+        let color = term::color(&state.color);
+        let color_reset = term::color(&term::Color::Reset);
+        let frame = state.symbols[iteration % state.symbols.len()];
+        let text = &state.text;
+        term::write(
+            term::Stream::Stdout,
+            &format!("\r{color}{frame}{color_reset} {text}\n"),
+        );
+    }
+    term::flush(term::Stream::Stdout);
+}
@@ -4,6 +4,7 @@ use std::sync::mpsc::{channel, Sender, TryRecvError};
 use std::thread::{sleep, spawn, JoinHandle};
 use std::time::Duration;
 
+use crate::spinners::Spinners;
 use crate::state::{State, Update};
 use crate::term;
 
@@ -114,6 +115,68 @@ impl<S> Spinner<S> {
         self.update.borrow_mut().frames_duration_ms = Some(ms);
         self
     }
+
+    /// Sets the symbols and frame duration from a named built-in preset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinach::{Spinner, Spinners};
+    ///
+    /// let spinner = Spinner::new("workin'...").preset(Spinners::Moon).start();
+    /// ```
+    pub fn preset(&self, preset: Spinners) -> &Self {
+        let (symbols, frames_duration_ms) = preset.frames();
+        let mut update = self.update.borrow_mut();
+        update.symbols = Some(symbols.to_vec());
+        update.frames_duration_ms = Some(frames_duration_ms);
+        self
+    }
+
+    /// Enables flicker-free rendering by wrapping each frame in a terminal
+    /// synchronized update (DEC private mode 2026).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinach::Spinner;
+    ///
+    /// let spinner = Spinner::new("workin'...").synchronized(true).start();
+    /// ```
+    pub fn synchronized(&self, enabled: bool) -> &Self {
+        self.update.borrow_mut().synchronized = Some(enabled);
+        self
+    }
+
+    /// Shows the elapsed running time, e.g. `(3.2s)`, after the text.
+    ///
+    /// The elapsed time is measured from when [`Spinner::start`] is called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinach::Spinner;
+    ///
+    /// let spinner = Spinner::new("Loading...").show_timer(true).start();
+    /// ```
+    pub fn show_timer(&self, enabled: bool) -> &Self {
+        self.update.borrow_mut().show_timer = Some(enabled);
+        self
+    }
+
+    /// Sets the stream the spinner is rendered to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinach::{Spinner, Stream};
+    ///
+    /// let spinner = Spinner::new("workin'...").stream(Stream::Stderr).start();
+    /// ```
+    pub fn stream(&self, stream: term::Stream) -> &Self {
+        self.update.borrow_mut().stream = Some(stream);
+        self
+    }
 }
 
 impl Spinner<Stopped> {
@@ -144,10 +207,10 @@ impl Spinner<Stopped> {
     /// let spinner = Spinner::new("let's go...").start();
     /// ```
     pub fn start(&self) -> Spinner<Running> {
-        term::hide_cursor();
         let (sender, receiver) = channel::<Update>();
         let mut state = State::default();
         state.update(self.update.take());
+        term::hide_cursor(state.stream);
         let handle = RefCell::new(Some(spawn(move || {
             let mut iteration = 0;
             loop {
@@ -171,8 +234,8 @@ impl Spinner<Stopped> {
                 iteration += 1;
                 sleep(Duration::from_millis(state.frames_duration_ms));
             }
-            term::new_line();
-            term::show_cursor();
+            term::new_line(state.stream);
+            term::show_cursor_on(state.stream);
         })));
         let handle = Rc::new(handle);
         Spinner {
@@ -227,6 +290,31 @@ impl Spinner<Running> {
         self.join();
     }
 
+    /// Stops the spinner, freezing the line with an arbitrary symbol,
+    /// color and text.
+    ///
+    /// This is the general form of [`success`](Self::success),
+    /// [`failure`](Self::failure) and [`warn`](Self::warn), for callers
+    /// who want a final glyph those don't cover.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinach::{Color, Spinner};
+    ///
+    /// let spinner = Spinner::new("Doing something...").start();
+    /// // Perform some task
+    /// spinner.stop_and_persist("📜", Color::default(), Some("done!"));
+    /// ```
+    pub fn stop_and_persist(&self, symbol: &'static str, color: term::Color, text: Option<&str>) {
+        self.update.borrow_mut().symbols = Some(vec![symbol]);
+        self.update.borrow_mut().color = Some(color);
+        if let Some(text) = text {
+            self.update.borrow_mut().text = Some(text.to_string());
+        }
+        self.stop();
+    }
+
     /// Stops the spinner with a pre-configured success indication.
     /// Sets the symbol and color.
     ///
@@ -0,0 +1,56 @@
+/// Named catalog of built-in spinner animations.
+///
+/// Pass a variant to [`Spinner::preset`](crate::Spinner::preset) to set both
+/// the animation frames and the frame duration in one call, instead of
+/// hand-typing a `Vec<&'static str>` of Unicode frames.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Spinners {
+    #[default]
+    Dots,
+    Dots2,
+    Line,
+    Arc,
+    Bounce,
+    Moon,
+    Clock,
+    Aesthetic,
+}
+
+impl Spinners {
+    /// Returns the animation frames and frame duration, in milliseconds,
+    /// for this preset.
+    pub(crate) fn frames(self) -> (&'static [&'static str], u64) {
+        match self {
+            Self::Dots => (
+                &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"],
+                80,
+            ),
+            Self::Dots2 => (&["⣾", "⣽", "⣻", "⢿", "⡿", "⣟", "⣯", "⣷"], 80),
+            Self::Line => (&["-", "\\", "|", "/"], 130),
+            Self::Arc => (&["◜", "◠", "◝", "◞", "◡", "◟"], 100),
+            Self::Bounce => (&["⠁", "⠂", "⠄", "⠂"], 120),
+            Self::Moon => (
+                &["🌑", "🌒", "🌓", "🌔", "🌕", "🌖", "🌗", "🌘"],
+                80,
+            ),
+            Self::Clock => (
+                &[
+                    "🕛", "🕐", "🕑", "🕒", "🕓", "🕔", "🕕", "🕖", "🕗", "🕘", "🕙", "🕚",
+                ],
+                100,
+            ),
+            Self::Aesthetic => (
+                &[
+                    "▰▱▱▱▱▱▱",
+                    "▰▰▱▱▱▱▱",
+                    "▰▰▰▱▱▱▱",
+                    "▰▰▰▰▱▱▱",
+                    "▰▰▰▰▰▱▱",
+                    "▰▰▰▰▰▰▱",
+                    "▰▰▰▰▰▰▰",
+                ],
+                80,
+            ),
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use std::time::Instant;
+
 use crate::term;
 
 /// Represents the state of a spinner or progress indicator.
@@ -10,6 +12,14 @@ pub struct State {
     pub symbols: Vec<&'static str>,
     /// The duration of each frame in milliseconds.
     pub frames_duration_ms: u64,
+    /// Whether to wrap each frame in a synchronized terminal update.
+    pub synchronized: bool,
+    /// When the spinner started running, used by `show_timer`.
+    pub start: Instant,
+    /// Whether to append the elapsed running time after the text.
+    pub show_timer: bool,
+    /// The stream the spinner is rendered to.
+    pub stream: term::Stream,
 }
 
 impl State {
@@ -27,6 +37,15 @@ impl State {
         if let Some(frames_duration_ms) = update.frames_duration_ms {
             self.frames_duration_ms = frames_duration_ms;
         }
+        if let Some(synchronized) = update.synchronized {
+            self.synchronized = synchronized;
+        }
+        if let Some(show_timer) = update.show_timer {
+            self.show_timer = show_timer;
+        }
+        if let Some(stream) = update.stream {
+            self.stream = stream;
+        }
     }
 
     /// Renders the current state of the spinner.
@@ -35,9 +54,20 @@ impl State {
         let frame = self.symbols.clone()[iteration];
         let color_reset = term::color(&term::Color::Reset);
         let text = &self.text;
-        term::delete_line();
-        print!("\r{color}{frame}{color_reset} {text}");
-        term::flush();
+        let timer = if self.show_timer {
+            format!(" ({:.1}s)", self.start.elapsed().as_secs_f64())
+        } else {
+            String::new()
+        };
+        if self.synchronized {
+            term::sync_update_begin(self.stream);
+        }
+        term::delete_line(self.stream);
+        term::write(self.stream, &format!("\r{color}{frame}{color_reset} {text}{timer}"));
+        if self.synchronized {
+            term::sync_update_end(self.stream);
+        }
+        term::flush(self.stream);
     }
 }
 
@@ -49,6 +79,10 @@ impl Default for State {
             color: term::Color::default(),
             symbols: vec!["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"],
             frames_duration_ms: 65,
+            synchronized: false,
+            start: Instant::now(),
+            show_timer: false,
+            stream: term::Stream::default(),
         }
     }
 }
@@ -66,6 +100,12 @@ pub struct Update {
     pub symbols: Option<Vec<&'static str>>,
     /// Optional new frame duration in milliseconds.
     pub frames_duration_ms: Option<u64>,
+    /// Optional new synchronized output toggle.
+    pub synchronized: Option<bool>,
+    /// Optional new elapsed-time display toggle.
+    pub show_timer: Option<bool>,
+    /// Optional new output stream.
+    pub stream: Option<term::Stream>,
 }
 
 impl Update {
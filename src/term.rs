@@ -1,4 +1,21 @@
-use std::io::{stdout, Write};
+use std::io::{stderr, stdout, Write};
+
+/// Selects which standard stream a spinner writes its output to.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Stream {
+    #[default]
+    Stdout,
+    Stderr,
+}
+
+impl Stream {
+    fn write(self, content: &str) {
+        match self {
+            Self::Stdout => print!("{content}"),
+            Self::Stderr => eprint!("{content}"),
+        }
+    }
+}
 
 /// Spinach supported color enum.
 #[derive(Clone, Debug)]
@@ -13,6 +30,10 @@ pub enum Color {
     Magenta,
     Cyan,
     White,
+    /// Truecolor, specified as `(red, green, blue)` components.
+    Rgb(u8, u8, u8),
+    /// A color from the 256-color palette.
+    Fixed(u8),
 }
 
 impl Default for Color {
@@ -21,19 +42,22 @@ impl Default for Color {
     }
 }
 
-pub(crate) fn flush() {
-    stdout().flush().unwrap();
+pub(crate) fn flush(stream: Stream) {
+    match stream {
+        Stream::Stdout => stdout().flush().unwrap(),
+        Stream::Stderr => stderr().flush().unwrap(),
+    }
 }
 
-pub(crate) fn delete_line() {
-    print!("\x1b[2K");
+pub(crate) fn delete_line(stream: Stream) {
+    stream.write("\x1b[2K");
 }
 
-pub(crate) fn hide_cursor() {
-    print!("\x1b[?25l");
+pub(crate) fn hide_cursor(stream: Stream) {
+    stream.write("\x1b[?25l");
 }
 
-/// Print show cursor ANSI escape code
+/// Print show cursor ANSI escape code to stdout.
 ///
 /// Can be used when managing ctrl^c/SIGINT to show the cursor back
 ///
@@ -50,8 +74,39 @@ pub fn show_cursor() {
     print!("\x1b[?25h");
 }
 
-pub(crate) fn new_line() {
-    println!();
+pub(crate) fn show_cursor_on(stream: Stream) {
+    stream.write("\x1b[?25h");
+}
+
+pub(crate) fn new_line(stream: Stream) {
+    stream.write("\n");
+}
+
+/// Begins a synchronized terminal update.
+///
+/// Terminals that support the DEC private mode 2026 buffer every byte
+/// written until [`sync_update_end`] is called, then present the whole
+/// frame atomically, avoiding tearing on fast refresh. Terminals that
+/// don't support it simply ignore the unknown escape sequence, so it's
+/// safe to always emit it when synchronized output is enabled.
+pub(crate) fn sync_update_begin(stream: Stream) {
+    stream.write("\x1b[?2026h");
+}
+
+/// Ends a synchronized terminal update started with [`sync_update_begin`].
+pub(crate) fn sync_update_end(stream: Stream) {
+    stream.write("\x1b[?2026l");
+}
+
+pub(crate) fn write(stream: Stream, content: &str) {
+    stream.write(content);
+}
+
+/// Moves the cursor up `lines` lines.
+pub(crate) fn cursor_up(stream: Stream, lines: usize) {
+    if lines > 0 {
+        stream.write(&format!("\x1b[{lines}A"));
+    }
 }
 
 pub(crate) fn color(color: &Color) -> String {
@@ -66,9 +121,63 @@ pub(crate) fn color(color: &Color) -> String {
         Color::Magenta => ansi_color(35),
         Color::Cyan => ansi_color(36),
         Color::White => ansi_color(37),
+        Color::Rgb(r, g, b) => format!("\x1b[38;2;{r};{g};{b}m"),
+        Color::Fixed(n) => format!("\x1b[38;5;{n}m"),
     }
 }
 
 fn ansi_color(code: u64) -> String {
     format!("\x1b[{code}m")
 }
+
+/// Parses a [`Color::Rgb`] from a `#rrggbb` hex string or an X-style
+/// `rgb:rr/gg/bb` string.
+///
+/// Each `rgb:` component may be 2 or 4 hex digits; 4-digit (16-bit)
+/// components are scaled down to 8-bit. Returns `None` if `input` doesn't
+/// match either form.
+///
+/// # Examples
+///
+/// ```
+/// use spinach::{parse_color, Color};
+///
+/// assert!(matches!(parse_color("#ff8800"), Some(Color::Rgb(255, 136, 0))));
+/// assert!(matches!(parse_color("rgb:ff/88/00"), Some(Color::Rgb(255, 136, 0))));
+/// ```
+pub fn parse_color(input: &str) -> Option<Color> {
+    if let Some(hex) = input.strip_prefix('#') {
+        if hex.len() != 6 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
+        let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
+        let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
+        return Some(Color::Rgb(r, g, b));
+    }
+    if let Some(spec) = input.strip_prefix("rgb:") {
+        let components: Vec<&str> = spec.split('/').collect();
+        if let [r, g, b] = components[..] {
+            return Some(Color::Rgb(
+                parse_rgb_component(r)?,
+                parse_rgb_component(g)?,
+                parse_rgb_component(b)?,
+            ));
+        }
+    }
+    None
+}
+
+fn parse_rgb_component(component: &str) -> Option<u8> {
+    if !component.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    match component.len() {
+        2 => u8::from_str_radix(component, 16).ok(),
+        4 => {
+            let value = u16::from_str_radix(component, 16).ok()?;
+            Some((value >> 8) as u8)
+        }
+        _ => None,
+    }
+}